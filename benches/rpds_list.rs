@@ -158,6 +158,36 @@ fn rpds_list_iterate(c: &mut Criterion) {
     });
 }
 
+fn rpds_list_zip_map(c: &mut Criterion) {
+    let limit = 10_000;
+    let mut list: List<usize> = List::new();
+
+    for i in 0..limit {
+        list.push_front_mut(i);
+    }
+
+    c.bench_function("rpds list zip map", move |b| {
+        b.iter(|| {
+            let zipped: List<usize> = list.iter().zip(list.iter()).map(|(a, b)| a + b).collect();
+
+            zipped
+        })
+    });
+}
+
+fn rpds_list_zip_map_vec(c: &mut Criterion) {
+    let limit = 10_000;
+    let mut list: List<usize> = List::new();
+
+    for i in 0..limit {
+        list.push_front_mut(i);
+    }
+
+    c.bench_function("rpds list zip map vec", move |b| {
+        b.iter(|| list.zip_map_vec(&list, |a, b| a + b))
+    });
+}
+
 criterion_group!(
     benches,
     rpds_list_push_front,
@@ -166,6 +196,8 @@ criterion_group!(
     rpds_list_drop_first_mut,
     rpds_list_reverse,
     rpds_list_reverse_mut,
-    rpds_list_iterate
+    rpds_list_iterate,
+    rpds_list_zip_map,
+    rpds_list_zip_map_vec
 );
 criterion_main!(benches);
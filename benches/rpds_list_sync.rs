@@ -158,6 +158,37 @@ fn rpds_list_sync_iterate(c: &mut Criterion) {
     });
 }
 
+fn rpds_list_sync_zip_map(c: &mut Criterion) {
+    let limit = 10_000;
+    let mut list: ListSync<usize> = ListSync::new_sync();
+
+    for i in 0..limit {
+        list.push_front_mut(i);
+    }
+
+    c.bench_function("rpds list sync zip map", move |b| {
+        b.iter(|| {
+            let zipped: ListSync<usize> =
+                list.iter().zip(list.iter()).map(|(a, b)| a + b).collect();
+
+            zipped
+        })
+    });
+}
+
+fn rpds_list_sync_zip_map_vec(c: &mut Criterion) {
+    let limit = 10_000;
+    let mut list: ListSync<usize> = ListSync::new_sync();
+
+    for i in 0..limit {
+        list.push_front_mut(i);
+    }
+
+    c.bench_function("rpds list sync zip map vec", move |b| {
+        b.iter(|| list.zip_map_vec(&list, |a, b| a + b))
+    });
+}
+
 criterion_group!(
     benches,
     rpds_list_sync_push_front,
@@ -166,6 +197,8 @@ criterion_group!(
     rpds_list_sync_drop_first_mut,
     rpds_list_sync_reverse,
     rpds_list_sync_reverse_mut,
-    rpds_list_sync_iterate
+    rpds_list_sync_iterate,
+    rpds_list_sync_zip_map,
+    rpds_list_sync_zip_map_vec
 );
 criterion_main!(benches);
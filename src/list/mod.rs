@@ -3,6 +3,8 @@
  * file, You can obtain one at http://mozilla.org/MPL/2.0/.
  */
 
+use crate::utils::DefaultBuildHasher;
+use crate::HashTrieMap;
 use alloc::vec::Vec;
 use archery::*;
 use core::borrow::Borrow;
@@ -207,6 +209,37 @@ where
         self.length += 1;
     }
 
+    /// Pushes `values` to the front of the list, so that they appear in the same order.
+    fn push_front_ptrs_mut(&mut self, values: Vec<SharedPointer<T, P>>) {
+        for v in values.into_iter().rev() {
+            self.push_front_ptr_mut(v);
+        }
+    }
+
+    /// Returns a new list with the elements before `index`.  The elements themselves are shared.
+    fn prefix_until(&self, index: usize) -> List<T, P> {
+        let values: Vec<SharedPointer<T, P>> =
+            self.iter_ptr().take(index).map(SharedPointer::clone).collect();
+        let mut prefix = List::new_with_ptr_kind();
+
+        prefix.push_front_ptrs_mut(values);
+
+        prefix
+    }
+
+    /// Returns the list that starts at `index`, sharing its structure with this list.
+    fn suffix_from(&self, index: usize) -> List<T, P> {
+        let mut suffix = self.clone();
+
+        for _ in 0..index {
+            if !suffix.drop_first_mut() {
+                break;
+            }
+        }
+
+        suffix
+    }
+
     #[must_use]
     pub fn push_front(&self, v: T) -> List<T, P> {
         let mut new_list = self.clone();
@@ -274,6 +307,467 @@ where
     pub(crate) fn iter_ptr(&self) -> IterPtr<'_, T, P> {
         IterPtr::new(self)
     }
+
+    /// Returns the index of the last element that satisfies `pred`, or `None` if no element
+    /// does.
+    #[must_use]
+    pub fn last_position<F: FnMut(&T) -> bool>(&self, mut pred: F) -> Option<usize> {
+        let mut position = None;
+
+        for (i, v) in self.iter().enumerate() {
+            if pred(v) {
+                position = Some(i);
+            }
+        }
+
+        position
+    }
+
+    /// Returns a list with the result of applying `f` to each element and its successor.  The
+    /// resulting list has one less element than this one, or is empty if this list has less than
+    /// two elements.
+    #[must_use]
+    pub fn zip_with_next<R, F: FnMut(&T, &T) -> R>(&self, mut f: F) -> List<R, P> {
+        self.iter().zip(self.iter().skip(1)).map(|(a, b)| f(a, b)).collect()
+    }
+
+    /// Returns a list where each element is the number of elements up to and including that
+    /// position that satisfy `pred`.
+    #[must_use]
+    pub fn running_count<F: FnMut(&T) -> bool>(&self, mut pred: F) -> List<usize, P> {
+        let mut count = 0;
+
+        self.iter()
+            .map(|v| {
+                if pred(v) {
+                    count += 1;
+                }
+                count
+            })
+            .collect()
+    }
+
+    /// Returns the list of all prefixes of this list, from the empty list up to the full list.
+    ///
+    /// Prefixes cannot share structure with each other, so the total size of the result is
+    /// Θ(n²).  The elements themselves are shared.
+    #[must_use]
+    pub fn prefixes(&self) -> List<List<T, P>, P> {
+        let values: Vec<&SharedPointer<T, P>> = self.iter_ptr().collect();
+        let mut prefixes = List::new_with_ptr_kind();
+
+        for i in (0..=values.len()).rev() {
+            let mut prefix = List::new_with_ptr_kind();
+
+            for v in values[..i].iter().rev() {
+                prefix.push_front_ptr_mut(SharedPointer::clone(v));
+            }
+
+            prefixes.push_front_mut(prefix);
+        }
+
+        prefixes
+    }
+
+    /// Returns the concatenation of the results of applying `f` to each element together with
+    /// its index.
+    #[must_use]
+    pub fn flat_map_indexed<U, I, F>(&self, mut f: F) -> List<U, P>
+    where
+        I: IntoIterator<Item = U>,
+        F: FnMut(usize, &T) -> I,
+    {
+        self.iter().enumerate().flat_map(|(i, v)| f(i, v)).collect()
+    }
+
+    /// Maps every element with `f`, threading an accumulator through the calls, and returns the
+    /// resulting list together with the final value of the accumulator.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error returned by `f`.  No further elements are processed after that.
+    pub fn try_fold_map<U, B, E, F>(&self, init: B, mut f: F) -> Result<(List<U, P>, B), E>
+    where
+        F: FnMut(B, &T) -> Result<(U, B), E>,
+    {
+        let mut vec: Vec<U> = Vec::with_capacity(self.len());
+        let mut acc = init;
+
+        for v in self.iter() {
+            let (u, new_acc) = f(acc, v)?;
+
+            vec.push(u);
+            acc = new_acc;
+        }
+
+        let mut list = List::new_with_ptr_kind();
+
+        for u in vec.into_iter().rev() {
+            list.push_front_mut(u);
+        }
+
+        Ok((list, acc))
+    }
+
+    /// Returns `true` if `suffix` is a suffix of this list.
+    #[must_use]
+    pub fn ends_with(&self, suffix: &List<T, P>) -> bool
+    where
+        T: PartialEq,
+    {
+        match self.len().checked_sub(suffix.len()) {
+            Some(skip) => self.iter().skip(skip).eq(suffix.iter()),
+            None => false,
+        }
+    }
+
+    /// Returns a list with the elements at indices `i` and `j` swapped.  Only the elements up to
+    /// the largest of the two indices are rebuilt; the rest of the list is shared.  Returns `None`
+    /// if and only if either index is out of range.
+    #[must_use]
+    pub fn swap(&self, i: usize, j: usize) -> Option<List<T, P>> {
+        if i >= self.len() || j >= self.len() {
+            return None;
+        }
+
+        if i == j {
+            return Some(self.clone());
+        }
+
+        let end = i.max(j) + 1;
+        let mut values: Vec<SharedPointer<T, P>> =
+            self.iter_ptr().take(end).map(SharedPointer::clone).collect();
+        let mut new_list = self.suffix_from(end);
+
+        values.swap(i, j);
+        new_list.push_front_ptrs_mut(values);
+
+        Some(new_list)
+    }
+
+    /// Returns a list with the element at index `from` moved to index `to`, keeping the relative
+    /// order of the other elements.  Only the elements up to the largest of the two indices are
+    /// rebuilt.  Returns `None` if and only if either index is out of range.
+    #[must_use]
+    pub fn move_element(&self, from: usize, to: usize) -> Option<List<T, P>> {
+        if from >= self.len() || to >= self.len() {
+            return None;
+        }
+
+        if from == to {
+            return Some(self.clone());
+        }
+
+        let end = from.max(to) + 1;
+        let mut values: Vec<SharedPointer<T, P>> =
+            self.iter_ptr().take(end).map(SharedPointer::clone).collect();
+        let mut new_list = self.suffix_from(end);
+        let v = values.remove(from);
+
+        values.insert(to, v);
+        new_list.push_front_ptrs_mut(values);
+
+        Some(new_list)
+    }
+
+    /// Returns the list split in chunks of `n` elements, counting from the end of the list.  The
+    /// first chunk will have less than `n` elements if the length of the list is not a multiple
+    /// of `n`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is zero.
+    #[must_use]
+    pub fn rchunks(&self, n: usize) -> List<List<T, P>, P> {
+        assert!(n != 0, "chunk size must be non-zero");
+
+        let values: Vec<&SharedPointer<T, P>> = self.iter_ptr().collect();
+        let mut chunks = List::new_with_ptr_kind();
+
+        for chunk_values in values.rchunks(n) {
+            let mut chunk = List::new_with_ptr_kind();
+
+            for v in chunk_values.iter().rev() {
+                chunk.push_front_ptr_mut(SharedPointer::clone(v));
+            }
+
+            chunks.push_front_mut(chunk);
+        }
+
+        chunks
+    }
+
+    /// Splits the list in segments and folds each one of them with `fold`, starting from the
+    /// value returned by `init`.  A new segment starts at every element for which `is_boundary`
+    /// returns `true`, and that element is part of the new segment.  Returns the list of the
+    /// folded values of each segment.
+    #[must_use]
+    pub fn fold_segments<B, Q, I, F>(&self, mut is_boundary: Q, init: I, mut fold: F) -> List<B, P>
+    where
+        Q: FnMut(&T) -> bool,
+        I: Fn() -> B,
+        F: FnMut(B, &T) -> B,
+    {
+        let mut segments: Vec<B> = Vec::new();
+        let mut current: Option<B> = None;
+
+        for v in self.iter() {
+            let acc = match current.take() {
+                Some(acc) if !is_boundary(v) => acc,
+                Some(acc) => {
+                    segments.push(acc);
+                    init()
+                }
+                None => init(),
+            };
+
+            current = Some(fold(acc, v));
+        }
+
+        segments.extend(current);
+
+        segments.into_iter().collect()
+    }
+
+    /// Returns the length of the longest suffix whose structure is shared by all the given lists.
+    /// Only structural sharing is considered: equal suffixes that do not share their nodes do not
+    /// count.
+    #[must_use]
+    pub fn longest_shared_suffix(lists: &[&List<T, P>]) -> usize {
+        let min_len = lists.iter().map(|list| list.len()).min().unwrap_or(0);
+        let mut nodes: Vec<Option<&SharedPointer<Node<T, P>, P>>> =
+            lists.iter().map(|list| list.node_at(list.len() - min_len)).collect();
+
+        for i in 0..min_len {
+            let all_shared = nodes.windows(2).all(|pair| match (pair[0], pair[1]) {
+                (Some(a), Some(b)) => SharedPointer::ptr_eq(a, b),
+                _ => false,
+            });
+
+            if all_shared {
+                return min_len - i;
+            }
+
+            for node in &mut nodes {
+                *node = node.and_then(|n| n.next.as_ref());
+            }
+        }
+
+        0
+    }
+
+    fn node_at(&self, index: usize) -> Option<&SharedPointer<Node<T, P>, P>> {
+        let mut node = self.head.as_ref();
+
+        for _ in 0..index {
+            node = node.and_then(|n| n.next.as_ref());
+        }
+
+        node
+    }
+
+    /// Returns a list where adjacent elements are merged with `f`.  Whenever `f(prev, cur)`
+    /// returns `Some(merged)`, both elements are replaced by `merged`, which is then considered for
+    /// merging with the next element.  If `f` returns `None` the elements are kept separate.
+    #[must_use]
+    pub fn coalesce<F: FnMut(&T, &T) -> Option<T>>(&self, mut f: F) -> List<T, P> {
+        let mut values: Vec<SharedPointer<T, P>> = Vec::new();
+        let mut iter = self.iter_ptr();
+        let mut prev: Option<SharedPointer<T, P>> = iter.next().cloned();
+
+        for cur in iter {
+            if let Some(p) = prev.take() {
+                match f(&p, cur) {
+                    Some(merged) => prev = Some(SharedPointer::new(merged)),
+                    None => {
+                        values.push(p);
+                        prev = Some(SharedPointer::clone(cur));
+                    }
+                }
+            }
+        }
+
+        values.extend(prev);
+
+        let mut new_list = List::new_with_ptr_kind();
+
+        new_list.push_front_ptrs_mut(values);
+
+        new_list
+    }
+
+    /// Returns a `Vec` with the result of applying `f` to the elements of this list and `other`
+    /// in lockstep.  The result has the length of the shortest of the two lists.
+    #[must_use]
+    pub fn zip_map_vec<U, R, F: FnMut(&T, &U) -> R>(&self, other: &List<U, P>, mut f: F) -> Vec<R> {
+        let mut vec: Vec<R> = Vec::with_capacity(self.len().min(other.len()));
+
+        for (a, b) in self.iter().zip(other.iter()) {
+            vec.push(f(a, b));
+        }
+
+        vec
+    }
+
+    /// Returns a list with the first `n` elements reversed, sharing the rest of the list.  If `n`
+    /// is greater than the length of the list the whole list is reversed.
+    #[must_use]
+    pub fn reverse_prefix(&self, n: usize) -> List<T, P> {
+        let mut new_list = self.suffix_from(n);
+
+        for v in self.iter_ptr().take(n) {
+            new_list.push_front_ptr_mut(SharedPointer::clone(v));
+        }
+
+        new_list
+    }
+
+    /// Returns an iterator over the elements of the list together with the number of strong
+    /// references to the node that holds each element.  A count greater than one means that the
+    /// node, and therefore the rest of the list from it onwards, is shared.
+    pub fn cells(&self) -> impl Iterator<Item = (&T, usize)> + '_ {
+        core::iter::successors(self.head.as_ref(), |node| node.next.as_ref())
+            .map(|node| (node.value.borrow(), SharedPointer::strong_count(node)))
+    }
+
+    /// Applies `f` to every element and returns the list of the `Ok` values and the list of the
+    /// `Err` values, both in the original order.
+    #[must_use]
+    pub fn map_split<U, E, F: FnMut(&T) -> Result<U, E>>(
+        &self,
+        mut f: F,
+    ) -> (List<U, P>, List<E, P>) {
+        let mut oks: Vec<U> = Vec::new();
+        let mut errs: Vec<E> = Vec::new();
+
+        for v in self.iter() {
+            match f(v) {
+                Ok(u) => oks.push(u),
+                Err(e) => errs.push(e),
+            }
+        }
+
+        (oks.into_iter().collect(), errs.into_iter().collect())
+    }
+
+    /// Returns the number of elements that satisfy `pred` and the number of elements that do
+    /// not, in a single pass.
+    #[must_use]
+    pub fn count_partition<F: FnMut(&T) -> bool>(&self, mut pred: F) -> (usize, usize) {
+        let matching = self.iter().filter(|v| pred(v)).count();
+
+        (matching, self.len() - matching)
+    }
+
+    /// Returns the element `n` positions from the end of the list, where `0` is the last element.
+    /// Returns `None` if and only if `n` is out of range.
+    #[must_use]
+    pub fn nth_from_end(&self, n: usize) -> Option<&T> {
+        if n >= self.len() {
+            return None;
+        }
+
+        // The length is known, so we can walk straight to the element without the two-pointer
+        // technique.
+        self.iter().nth(self.len() - 1 - n)
+    }
+
+    /// Returns a list with the elements for which the corresponding element of `selectors` is
+    /// `true`.  Elements beyond the length of `selectors` are discarded.
+    #[must_use]
+    pub fn compress(&self, selectors: &List<bool, P>) -> List<T, P> {
+        let values: Vec<SharedPointer<T, P>> = self
+            .iter_ptr()
+            .zip(selectors.iter())
+            .filter(|(_, selected)| **selected)
+            .map(|(v, _)| SharedPointer::clone(v))
+            .collect();
+        let mut new_list = List::new_with_ptr_kind();
+
+        new_list.push_front_ptrs_mut(values);
+
+        new_list
+    }
+
+    /// Splits the list in two at `index`.  The first list contains the elements before `index`
+    /// and the second one, which shares its structure with this list, contains the rest.
+    ///
+    /// # Errors
+    ///
+    /// Returns `Err` with the length of the list if `index` is greater than the length of the
+    /// list.
+    pub fn try_split_at(&self, index: usize) -> Result<(List<T, P>, List<T, P>), usize> {
+        if index > self.len() {
+            return Err(self.len());
+        }
+
+        Ok((self.prefix_until(index), self.suffix_from(index)))
+    }
+
+    /// Returns a list with the first `n` elements of this list.  Returns `None` if and only if
+    /// the list has less than `n` elements.
+    #[must_use]
+    pub fn take_exact(&self, n: usize) -> Option<List<T, P>> {
+        if n > self.len() {
+            return None;
+        }
+
+        if n == self.len() {
+            return Some(self.clone());
+        }
+
+        Some(self.prefix_until(n))
+    }
+
+    /// Returns the concatenation of this list with `other`.  The result shares the structure of
+    /// `other`, so only this list is rebuilt.  If either list is empty this is Θ(1).
+    #[must_use]
+    pub fn concat(&self, other: &List<T, P>) -> List<T, P> {
+        if self.is_empty() {
+            return other.clone();
+        }
+
+        if other.is_empty() {
+            return self.clone();
+        }
+
+        let values: Vec<SharedPointer<T, P>> = self.iter_ptr().map(SharedPointer::clone).collect();
+        let mut new_list = other.clone();
+
+        new_list.push_front_ptrs_mut(values);
+
+        new_list
+    }
+
+    /// Splits the list in `k` contiguous sublists with sizes as even as possible.  When the length
+    /// of the list is not a multiple of `k` the first sublists get one extra element.  If `k` is
+    /// greater than the length of the list the last sublists are empty, and if `k` is zero the
+    /// result is empty.
+    #[must_use]
+    pub fn split_into(&self, k: usize) -> List<List<T, P>, P> {
+        if k == 0 {
+            return List::new_with_ptr_kind();
+        }
+
+        let base_size = self.len() / k;
+        let extra = self.len() % k;
+        let mut sublists: Vec<List<T, P>> = Vec::with_capacity(k);
+        let mut rest = self.clone();
+
+        for i in 0..k {
+            let size = if i < extra { base_size + 1 } else { base_size };
+
+            // The last non-empty sublist is a suffix of the list, so it can be shared.
+            if size == rest.len() {
+                sublists.push(core::mem::take(&mut rest));
+            } else {
+                sublists.push(rest.prefix_until(size));
+                rest = rest.suffix_from(size);
+            }
+        }
+
+        sublists.into_iter().collect()
+    }
 }
 
 impl<T, P> List<T, P>
@@ -287,6 +781,94 @@ where
             .as_mut()
             .map(|node| SharedPointer::make_mut(&mut SharedPointer::make_mut(node).value))
     }
+
+    /// Returns a `Vec` with the elements of the list in sorted order.  The sort is stable.
+    #[must_use]
+    pub fn to_sorted_vec(&self) -> Vec<T>
+    where
+        T: Ord,
+    {
+        let mut vec: Vec<T> = self.iter().cloned().collect();
+
+        vec.sort();
+
+        vec
+    }
+
+    /// Returns a `Vec` with the elements of the list sorted with the comparator function
+    /// `compare`.  The sort is stable.
+    #[must_use]
+    pub fn to_sorted_vec_by<F: FnMut(&T, &T) -> Ordering>(&self, compare: F) -> Vec<T> {
+        let mut vec: Vec<T> = self.iter().cloned().collect();
+
+        vec.sort_by(compare);
+
+        vec
+    }
+
+    /// Returns a `Vec` with the elements of the list sorted by the key extracted with `f`.  The
+    /// sort is stable.
+    #[must_use]
+    pub fn to_sorted_vec_by_key<K: Ord, F: FnMut(&T) -> K>(&self, f: F) -> Vec<T> {
+        let mut vec: Vec<T> = self.iter().cloned().collect();
+
+        vec.sort_by_key(f);
+
+        vec
+    }
+
+    /// Returns a list with the elements at the given indices replaced by the given values.  The
+    /// list is rebuilt only once, up to the largest index.  If an index appears more than once
+    /// the last value given for it prevails.  Returns `None` if and only if any index is out of
+    /// range.
+    #[must_use]
+    pub fn update_many(&self, updates: &[(usize, T)]) -> Option<List<T, P>> {
+        if updates.iter().any(|&(i, _)| i >= self.len()) {
+            return None;
+        }
+
+        let end = updates.iter().map(|&(i, _)| i + 1).max().unwrap_or(0);
+        let mut values: Vec<SharedPointer<T, P>> =
+            self.iter_ptr().take(end).map(SharedPointer::clone).collect();
+        let mut new_list = self.suffix_from(end);
+
+        for (i, v) in updates {
+            values[*i] = SharedPointer::new(v.clone());
+        }
+
+        new_list.push_front_ptrs_mut(values);
+
+        Some(new_list)
+    }
+
+    /// Returns an iterator over clones of the elements of the list.
+    pub fn iter_cloned(&self) -> impl ExactSizeIterator<Item = T> + '_ {
+        self.iter().cloned()
+    }
+
+    /// Returns the distinct elements of the list, in the order they first appear, each paired
+    /// with the number of times it occurs in the list.
+    #[must_use]
+    pub fn distinct_with_counts(&self) -> List<(T, usize), P>
+    where
+        T: Hash + Eq,
+    {
+        let mut indices: HashTrieMap<&T, usize, P, DefaultBuildHasher> =
+            HashTrieMap::new_with_hasher_and_ptr_kind(DefaultBuildHasher::default());
+        let mut counts: Vec<(T, usize)> = Vec::new();
+
+        for v in self.iter() {
+            match indices.get(v) {
+                Some(&i) => counts[i].1 += 1,
+                None => {
+                    indices.insert_mut(v, counts.len());
+                    counts.push((v.clone(), 1));
+                }
+            }
+        }
+
+        counts.into_iter().collect()
+    }
 }
 
 impl<T, P> Default for List<T, P>
@@ -472,7 +1054,7 @@ impl<'a, T, P> ExactSizeIterator for IterPtr<'a, T, P> where P: SharedPointerKin
 pub mod serde {
     use super::*;
     use ::serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
-    use ::serde::ser::{Serialize, Serializer};
+    use ::serde::ser::{Serialize, SerializeSeq, Serializer};
     use core::fmt;
     use core::marker::PhantomData;
 
@@ -482,7 +1064,15 @@ pub mod serde {
         P: SharedPointerKind,
     {
         fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
-            serializer.collect_seq(self)
+            // Serialize the elements directly from the list so that no intermediate collection is
+            // ever created, even for huge lists.
+            let mut seq = serializer.serialize_seq(Some(self.len()))?;
+
+            for e in self {
+                seq.serialize_element(e)?;
+            }
+
+            seq.end()
         }
     }
 
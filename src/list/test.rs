@@ -200,6 +200,453 @@ fn test_reverse_mut() {
     assert_eq!(list.last(), Some(&0));
 }
 
+#[test]
+fn test_last_position() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 0, 1, 0];
+
+    assert_eq!(empty_list.last_position(|x| *x == 1), None);
+    assert_eq!(list.last_position(|x| *x == 1), Some(3));
+    assert_eq!(list.last_position(|x| *x == 0), Some(4));
+    assert_eq!(list.last_position(|x| *x == 2), None);
+}
+
+#[test]
+fn test_zip_with_next() {
+    let empty_list: List<i32> = List::new();
+    let singleton_list = list![0];
+    let list = list![1, 3, 2, 4, 4];
+
+    assert_eq!(empty_list.zip_with_next(|a, b| a < b), List::<bool>::new());
+    assert_eq!(singleton_list.zip_with_next(|a, b| a < b), List::<bool>::new());
+    assert_eq!(list.zip_with_next(|a, b| a < b), list![true, false, true, false]);
+    assert_eq!(list.zip_with_next(|a, b| b - a), list![2, -1, 2, 0]);
+}
+
+#[test]
+fn test_running_count() {
+    let empty_list: List<i32> = List::new();
+    let list = list![1, 0, 1, 1];
+
+    assert_eq!(empty_list.running_count(|x| *x == 1), List::<usize>::new());
+    assert_eq!(list.running_count(|x| *x == 1), list![1, 1, 2, 3]);
+    assert_eq!(list.running_count(|x| *x == 0), list![0, 1, 1, 1]);
+}
+
+#[test]
+fn test_to_sorted_vec() {
+    let empty_list: List<i32> = List::new();
+    let list = list![3, 1, 4, 1, 5, 9, 2, 6];
+    let pairs = list![(2, 'a'), (1, 'b'), (2, 'c'), (1, 'd'), (0, 'e')];
+
+    assert_eq!(empty_list.to_sorted_vec(), Vec::<i32>::new());
+    assert_eq!(list.to_sorted_vec(), vec![1, 1, 2, 3, 4, 5, 6, 9]);
+    assert_eq!(list.to_sorted_vec_by(|a, b| b.cmp(a)), vec![9, 6, 5, 4, 3, 2, 1, 1]);
+    assert_eq!(
+        pairs.to_sorted_vec_by_key(|&(k, _)| k),
+        vec![(0, 'e'), (1, 'b'), (1, 'd'), (2, 'a'), (2, 'c')]
+    );
+    assert_eq!(
+        pairs.to_sorted_vec_by(|a, b| b.0.cmp(&a.0)),
+        vec![(2, 'a'), (2, 'c'), (1, 'b'), (1, 'd'), (0, 'e')]
+    );
+}
+
+#[test]
+fn test_prefixes() {
+    let empty_list: List<i32> = List::new();
+    let list = list![1, 2];
+
+    assert_eq!(empty_list.prefixes(), list![list![]]);
+    assert_eq!(list.prefixes(), list![list![], list![1], list![1, 2]]);
+    assert_eq!(list.prefixes().last().unwrap().last(), Some(&2));
+}
+
+#[test]
+fn test_flat_map_indexed() {
+    let empty_list: List<&str> = List::new();
+    let list = list!["a", "b"];
+
+    assert_eq!(empty_list.flat_map_indexed(|_, x| Some(*x)), List::<&str>::new());
+    assert_eq!(
+        list.flat_map_indexed(|i, x| core::iter::repeat(*x).take(i + 1)),
+        list!["a", "b", "b"]
+    );
+    assert_eq!(list.flat_map_indexed(|i, x| if i == 0 { None } else { Some(*x) }), list!["b"]);
+}
+
+#[test]
+fn test_try_fold_map() {
+    fn parse(offset: usize, token: &&str) -> Result<((usize, u32), usize), usize> {
+        match token.parse() {
+            Ok(n) => Ok(((offset, n), offset + token.len())),
+            Err(_) => Err(offset),
+        }
+    }
+
+    let empty_list: List<&str> = List::new();
+    let list = list!["1", "23", "456"];
+    let list_bad = list!["1", "2x", "3", "y"];
+
+    assert_eq!(empty_list.try_fold_map(0, parse), Ok((List::new(), 0)));
+    assert_eq!(list.try_fold_map(0, parse), Ok((list![(0, 1), (1, 23), (3, 456)], 6)));
+    assert_eq!(list_bad.try_fold_map(0, parse), Err(1));
+}
+
+#[test]
+fn test_ends_with() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3];
+
+    assert!(empty_list.ends_with(&empty_list));
+    assert!(list.ends_with(&empty_list));
+    assert!(list.ends_with(&list![3]));
+    assert!(list.ends_with(&list![2, 3]));
+    assert!(list.ends_with(&list![0, 1, 2, 3]));
+    assert!(!list.ends_with(&list![1, 3]));
+    assert!(!list.ends_with(&list![2]));
+    assert!(!list.ends_with(&list![-1, 0, 1, 2, 3]));
+    assert!(!empty_list.ends_with(&list![0]));
+}
+
+#[test]
+fn test_swap() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3, 4];
+
+    assert_eq!(empty_list.swap(0, 0), None);
+    assert_eq!(list.swap(0, 1), Some(list![1, 0, 2, 3, 4]));
+    assert_eq!(list.swap(3, 2), Some(list![0, 1, 3, 2, 4]));
+    assert_eq!(list.swap(0, 4), Some(list![4, 1, 2, 3, 0]));
+    assert_eq!(list.swap(0, 4).unwrap().last(), Some(&0));
+    assert_eq!(list.swap(2, 2), Some(list.clone()));
+    assert_eq!(list.swap(0, 5), None);
+    assert_eq!(list.swap(5, 0), None);
+
+    let swapped = list.swap(1, 2).unwrap();
+    let tail = swapped.suffix_from(3);
+
+    assert!(SharedPointer::ptr_eq(
+        tail.head.as_ref().unwrap(),
+        list.suffix_from(3).head.as_ref().unwrap()
+    ));
+    assert_eq!(swapped.len(), 5);
+    assert_eq!(swapped.last(), Some(&4));
+}
+
+#[test]
+fn test_move_element() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3, 4];
+
+    assert_eq!(empty_list.move_element(0, 0), None);
+    assert_eq!(list.move_element(1, 3), Some(list![0, 2, 3, 1, 4]));
+    assert_eq!(list.move_element(0, 4), Some(list![1, 2, 3, 4, 0]));
+    assert_eq!(list.move_element(0, 4).unwrap().last(), Some(&0));
+    assert_eq!(list.move_element(3, 0), Some(list![3, 0, 1, 2, 4]));
+    assert_eq!(list.move_element(4, 1), Some(list![0, 4, 1, 2, 3]));
+    assert_eq!(list.move_element(4, 1).unwrap().last(), Some(&3));
+    assert_eq!(list.move_element(2, 2), Some(list.clone()));
+    assert_eq!(list.move_element(5, 0), None);
+    assert_eq!(list.move_element(0, 5), None);
+}
+
+#[test]
+fn test_rchunks() {
+    let empty_list: List<i32> = List::new();
+    let list = list![1, 2, 3, 4, 5];
+
+    assert_eq!(empty_list.rchunks(2), List::<List<i32>>::new());
+    assert_eq!(list.rchunks(2), list![list![1], list![2, 3], list![4, 5]]);
+    assert_eq!(list.rchunks(1), list![list![1], list![2], list![3], list![4], list![5]]);
+    assert_eq!(list.rchunks(5), list![list![1, 2, 3, 4, 5]]);
+    assert_eq!(list.rchunks(7), list![list![1, 2, 3, 4, 5]]);
+    assert_eq!(list.rchunks(2).first().unwrap().last(), Some(&1));
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_rchunks_zero() {
+    let _ = list![1, 2, 3].rchunks(0);
+}
+
+#[test]
+fn test_fold_segments() {
+    let empty_list: List<i32> = List::new();
+    let list = list![1, 2, 0, 3, 4, 0, 5];
+    let list_leading_boundary = list![0, 1, 0, 0, 2];
+
+    assert_eq!(empty_list.fold_segments(|x| *x == 0, || 0, |acc, x| acc + x), List::new());
+    assert_eq!(list.fold_segments(|x| *x == 0, || 0, |acc, x| acc + x), list![3, 7, 5]);
+    assert_eq!(list.fold_segments(|x| *x == 0, || 0, |acc, _| acc + 1), list![2, 3, 2]);
+    assert_eq!(
+        list_leading_boundary.fold_segments(|x| *x == 0, || 0, |acc, _| acc + 1),
+        list![2, 1, 2]
+    );
+    assert_eq!(list.fold_segments(|_| false, || 0, |acc, x| acc + x), list![15]);
+}
+
+#[test]
+fn test_longest_shared_suffix() {
+    let base = list![3, 4, 5];
+    let a = base.push_front(2).push_front(1);
+    let b = base.push_front(9);
+    let c = base.drop_first().unwrap().push_front(3).push_front(2);
+    let unrelated = list![3, 4, 5];
+    let empty_list: List<i32> = List::new();
+
+    assert_eq!(List::longest_shared_suffix(&[&a, &b, &base]), 3);
+    assert_eq!(List::longest_shared_suffix(&[&a, &b, &c]), 2);
+    assert_eq!(List::longest_shared_suffix(&[&a, &b]), 3);
+    assert_eq!(List::longest_shared_suffix(&[&a]), 5);
+    assert_eq!(List::longest_shared_suffix(&[&a, &unrelated]), 0);
+    assert_eq!(List::longest_shared_suffix(&[&a, &empty_list]), 0);
+    assert_eq!(List::<i32>::longest_shared_suffix(&[]), 0);
+}
+
+#[test]
+fn test_update_many() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3, 4];
+
+    assert_eq!(empty_list.update_many(&[]), Some(List::new()));
+    assert_eq!(empty_list.update_many(&[(0, 1)]), None);
+    assert_eq!(list.update_many(&[]), Some(list.clone()));
+    assert_eq!(list.update_many(&[(3, 30), (1, 10)]), Some(list![0, 10, 2, 30, 4]));
+    assert_eq!(list.update_many(&[(4, 40), (0, 0)]).unwrap().last(), Some(&40));
+    assert_eq!(list.update_many(&[(2, 20), (2, 21)]), Some(list![0, 1, 21, 3, 4]));
+    assert_eq!(list.update_many(&[(1, 10), (5, 50)]), None);
+
+    let updated = list.update_many(&[(0, 10), (2, 20)]).unwrap();
+
+    assert!(SharedPointer::ptr_eq(updated.node_at(3).unwrap(), list.node_at(3).unwrap()));
+}
+
+#[test]
+fn test_coalesce() {
+    let merge = |a: &(i32, i32), b: &(i32, i32)| {
+        if b.0 <= a.1 {
+            Some((a.0, a.1.max(b.1)))
+        } else {
+            None
+        }
+    };
+
+    let empty_list: List<(i32, i32)> = List::new();
+    let singleton_list = list![(1, 2)];
+    let list = list![(1, 3), (2, 4), (4, 5), (7, 8), (9, 12), (10, 11)];
+
+    assert_eq!(empty_list.coalesce(merge), List::new());
+    assert_eq!(singleton_list.coalesce(merge), list![(1, 2)]);
+    assert_eq!(list.coalesce(merge), list![(1, 5), (7, 8), (9, 12)]);
+    assert_eq!(list.coalesce(merge).last(), Some(&(9, 12)));
+    assert_eq!(list.coalesce(|_, _| None), list);
+}
+
+#[test]
+fn test_zip_map_vec() {
+    let empty_list: List<i32> = List::new();
+    let list_a = list![1, 2, 3, 4];
+    let list_b = list![10, 20, 30];
+    let zipped: List<i32> = list_a.iter().zip(list_b.iter()).map(|(a, b)| a * b).collect();
+
+    assert_eq!(empty_list.zip_map_vec(&list_b, |a, b| a * b), Vec::<i32>::new());
+    assert_eq!(list_a.zip_map_vec(&list_b, |a, b| a * b), vec![10, 40, 90]);
+    assert_eq!(list_b.zip_map_vec(&list_a, |a, b| a * b), vec![10, 40, 90]);
+    assert!(list_a.zip_map_vec(&list_b, |a, b| a * b).iter().eq(zipped.iter()));
+}
+
+#[test]
+fn test_reverse_prefix() {
+    let empty_list: List<i32> = List::new();
+    let list = list![1, 2, 3, 4];
+
+    assert_eq!(empty_list.reverse_prefix(2), List::new());
+    assert_eq!(list.reverse_prefix(0), list);
+    assert_eq!(list.reverse_prefix(1), list);
+    assert_eq!(list.reverse_prefix(3), list![3, 2, 1, 4]);
+    assert_eq!(list.reverse_prefix(3).last(), Some(&4));
+    assert_eq!(list.reverse_prefix(4), list![4, 3, 2, 1]);
+    assert_eq!(list.reverse_prefix(4).last(), Some(&1));
+    assert_eq!(list.reverse_prefix(10), list![4, 3, 2, 1]);
+
+    assert!(SharedPointer::ptr_eq(
+        list.reverse_prefix(2).node_at(2).unwrap(),
+        list.node_at(2).unwrap()
+    ));
+}
+
+#[test]
+fn test_cells() {
+    let empty_list: List<i32> = List::new();
+    let base = list![3, 4];
+    let list_a = base.push_front(1);
+    let list_b = base.push_front(2);
+
+    assert_eq!(empty_list.cells().count(), 0);
+    assert_eq!(list_a.cells().collect::<Vec<_>>(), vec![(&1, 1), (&3, 3), (&4, 1)]);
+    assert_eq!(list_b.cells().collect::<Vec<_>>(), vec![(&2, 1), (&3, 3), (&4, 1)]);
+
+    drop(base);
+
+    assert_eq!(list_a.cells().collect::<Vec<_>>(), vec![(&1, 1), (&3, 2), (&4, 1)]);
+}
+
+#[test]
+fn test_map_split() {
+    let parse = |s: &&str| s.parse::<i32>().map_err(|_| s.len());
+    let empty_list: List<&str> = List::new();
+    let list = list!["1", "a", "22", "bbb", "3"];
+    let list_ok = list!["1", "2"];
+
+    assert_eq!(empty_list.map_split(parse), (List::new(), List::new()));
+    assert_eq!(list.map_split(parse), (list![1, 22, 3], list![1, 3]));
+    assert_eq!(list_ok.map_split(parse), (list![1, 2], List::new()));
+}
+
+#[test]
+fn test_count_partition() {
+    let empty_list: List<i32> = List::new();
+    let list = list![1, 2, 3, 4, 5, 6, 7];
+
+    assert_eq!(empty_list.count_partition(|x| *x % 2 == 0), (0, 0));
+    assert_eq!(list.count_partition(|x| *x % 2 == 0), (3, 4));
+    assert_eq!(list.count_partition(|x| *x > 10), (0, 7));
+
+    let (matching, non_matching) = list.count_partition(|x| *x < 3);
+
+    assert_eq!(matching + non_matching, list.len());
+}
+
+#[test]
+fn test_nth_from_end() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3];
+
+    assert_eq!(empty_list.nth_from_end(0), None);
+    assert_eq!(list.nth_from_end(0), Some(&3));
+    assert_eq!(list.nth_from_end(1), Some(&2));
+    assert_eq!(list.nth_from_end(3), Some(&0));
+    assert_eq!(list.nth_from_end(4), None);
+    assert_eq!(list.nth_from_end(usize::MAX), None);
+}
+
+#[test]
+fn test_compress() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3, 4];
+
+    assert_eq!(empty_list.compress(&list![true]), List::new());
+    assert_eq!(list.compress(&List::new()), List::new());
+    assert_eq!(list.compress(&list![true, false, true, true, false]), list![0, 2, 3]);
+    assert_eq!(list.compress(&list![false, true]), list![1]);
+    assert_eq!(list.compress(&list![true, true, true, true, true, true]), list);
+    assert_eq!(list.compress(&list![true, false, false, false, true]).last(), Some(&4));
+}
+
+#[test]
+fn test_try_split_at() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3];
+
+    assert_eq!(empty_list.try_split_at(0), Ok((List::new(), List::new())));
+    assert_eq!(empty_list.try_split_at(1), Err(0));
+    assert_eq!(list.try_split_at(0), Ok((List::new(), list![0, 1, 2, 3])));
+    assert_eq!(list.try_split_at(1), Ok((list![0], list![1, 2, 3])));
+    assert_eq!(list.try_split_at(3), Ok((list![0, 1, 2], list![3])));
+    assert_eq!(list.try_split_at(4), Ok((list![0, 1, 2, 3], List::new())));
+    assert_eq!(list.try_split_at(5), Err(4));
+
+    let (prefix, suffix) = list.try_split_at(2).unwrap();
+
+    assert_eq!(prefix.last(), Some(&1));
+    assert_eq!(suffix.last(), Some(&3));
+    assert!(SharedPointer::ptr_eq(suffix.head.as_ref().unwrap(), list.node_at(2).unwrap()));
+}
+
+#[test]
+fn test_iter_cloned() {
+    let empty_list: List<String> = List::new();
+    let list = list![String::from("a"), String::from("b"), String::from("c")];
+    let mut iterator = list.iter_cloned();
+
+    assert_eq!(empty_list.iter_cloned().size_hint(), (0, Some(0)));
+    assert_eq!(iterator.size_hint(), (3, Some(3)));
+
+    iterator.next();
+
+    assert_eq!(iterator.len(), 2);
+    assert_eq!(list.iter_cloned().collect::<Vec<String>>(), vec!["a", "b", "c"]);
+}
+
+#[test]
+fn test_take_exact() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3];
+
+    assert_eq!(empty_list.take_exact(0), Some(List::new()));
+    assert_eq!(empty_list.take_exact(1), None);
+    assert_eq!(list.take_exact(0), Some(List::new()));
+    assert_eq!(list.take_exact(2), Some(list![0, 1]));
+    assert_eq!(list.take_exact(2).unwrap().last(), Some(&1));
+    assert_eq!(list.take_exact(4), Some(list.clone()));
+    assert_eq!(list.take_exact(5), None);
+}
+
+#[test]
+fn test_concat() {
+    let empty_list: List<i32> = List::new();
+    let list_a = list![0, 1, 2];
+    let list_b = list![3, 4];
+
+    assert_eq!(empty_list.concat(&empty_list), List::new());
+    assert!(SharedPointer::ptr_eq(
+        empty_list.concat(&list_b).head.as_ref().unwrap(),
+        list_b.head.as_ref().unwrap()
+    ));
+    assert!(SharedPointer::ptr_eq(
+        list_a.concat(&empty_list).head.as_ref().unwrap(),
+        list_a.head.as_ref().unwrap()
+    ));
+
+    let concatenated = list_a.concat(&list_b);
+
+    assert_eq!(concatenated, list![0, 1, 2, 3, 4]);
+    assert_eq!(concatenated.len(), 5);
+    assert_eq!(concatenated.last(), Some(&4));
+    assert!(SharedPointer::ptr_eq(concatenated.node_at(3).unwrap(), list_b.node_at(0).unwrap()));
+}
+
+#[test]
+fn test_distinct_with_counts() {
+    let empty_list: List<&str> = List::new();
+    let list = list!["b", "a", "b", "c", "a"];
+
+    assert_eq!(empty_list.distinct_with_counts(), List::new());
+    assert_eq!(list.distinct_with_counts(), list![("b", 2), ("a", 2), ("c", 1)]);
+    assert_eq!(list![1, 1, 1].distinct_with_counts(), list![(1, 3)]);
+}
+
+#[test]
+fn test_split_into() {
+    let empty_list: List<i32> = List::new();
+    let list = list![0, 1, 2, 3, 4, 5, 6];
+    let sizes = |l: &List<List<i32>>| l.iter().map(List::len).collect::<Vec<usize>>();
+
+    assert_eq!(empty_list.split_into(0), List::new());
+    assert_eq!(empty_list.split_into(2), list![List::new(), List::new()]);
+    assert_eq!(list.split_into(0), List::new());
+    assert_eq!(list.split_into(1), list![list.clone()]);
+    assert_eq!(list.split_into(3), list![list![0, 1, 2], list![3, 4], list![5, 6]]);
+    assert_eq!(sizes(&list.split_into(3)), vec![3, 2, 2]);
+    assert_eq!(sizes(&list.split_into(7)), vec![1; 7]);
+    assert_eq!(sizes(&list![0, 1].split_into(4)), vec![1, 1, 0, 0]);
+    assert_eq!(
+        list.split_into(3).iter().map(List::last).collect::<Vec<_>>(),
+        vec![Some(&2), Some(&4), Some(&6)]
+    );
+}
+
 #[test]
 fn test_from_iterator() {
     let vec: Vec<u32> = vec![10, 11, 12, 13];
@@ -406,3 +853,179 @@ fn test_serde() {
 
     assert_eq!(list, decoded);
 }
+
+#[cfg(feature = "serde")]
+mod serde {
+    use super::*;
+    use ::serde::ser::{Impossible, Serialize, SerializeSeq, Serializer};
+    use core::fmt;
+    use pretty_assertions::assert_eq;
+
+    /// A serializer that only supports sequences, and records how the sequence was serialized.
+    #[derive(Default)]
+    struct SeqCounter {
+        seq_len_hint: Option<usize>,
+        elements: usize,
+        ended: bool,
+    }
+
+    macro_rules! unsupported {
+        ($($method:ident($($arg:ty),*);)*) => {
+            $(
+                fn $method(self, $(_: $arg),*) -> Result<(), fmt::Error> {
+                    Err(fmt::Error)
+                }
+            )*
+        };
+    }
+
+    impl Serializer for &mut SeqCounter {
+        type Ok = ();
+        type Error = fmt::Error;
+        type SerializeSeq = Self;
+        type SerializeTuple = Impossible<(), fmt::Error>;
+        type SerializeTupleStruct = Impossible<(), fmt::Error>;
+        type SerializeTupleVariant = Impossible<(), fmt::Error>;
+        type SerializeMap = Impossible<(), fmt::Error>;
+        type SerializeStruct = Impossible<(), fmt::Error>;
+        type SerializeStructVariant = Impossible<(), fmt::Error>;
+
+        unsupported! {
+            serialize_bool(bool);
+            serialize_i8(i8);
+            serialize_i16(i16);
+            serialize_i32(i32);
+            serialize_i64(i64);
+            serialize_u8(u8);
+            serialize_u16(u16);
+            serialize_u32(u32);
+            serialize_u64(u64);
+            serialize_f32(f32);
+            serialize_f64(f64);
+            serialize_char(char);
+            serialize_str(&str);
+            serialize_bytes(&[u8]);
+            serialize_none();
+            serialize_unit();
+            serialize_unit_struct(&'static str);
+            serialize_unit_variant(&'static str, u32, &'static str);
+        }
+
+        fn serialize_some<T: ?Sized + Serialize>(self, _: &T) -> Result<(), fmt::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_newtype_struct<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: &T,
+        ) -> Result<(), fmt::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_newtype_variant<T: ?Sized + Serialize>(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: &T,
+        ) -> Result<(), fmt::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_seq(self, len: Option<usize>) -> Result<Self, fmt::Error> {
+            self.seq_len_hint = len;
+            Ok(self)
+        }
+
+        fn serialize_tuple(self, _: usize) -> Result<Self::SerializeTuple, fmt::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_tuple_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleStruct, fmt::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_tuple_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeTupleVariant, fmt::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_map(self, _: Option<usize>) -> Result<Self::SerializeMap, fmt::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_struct(
+            self,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStruct, fmt::Error> {
+            Err(fmt::Error)
+        }
+
+        fn serialize_struct_variant(
+            self,
+            _: &'static str,
+            _: u32,
+            _: &'static str,
+            _: usize,
+        ) -> Result<Self::SerializeStructVariant, fmt::Error> {
+            Err(fmt::Error)
+        }
+    }
+
+    impl SerializeSeq for &mut SeqCounter {
+        type Ok = ();
+        type Error = fmt::Error;
+
+        fn serialize_element<T: ?Sized + Serialize>(&mut self, _: &T) -> Result<(), fmt::Error> {
+            assert!(!self.ended);
+            self.elements += 1;
+            Ok(())
+        }
+
+        fn end(self) -> Result<(), fmt::Error> {
+            self.ended = true;
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_serialize_streams_elements() {
+        let empty_list: List<i32> = List::new();
+        let list: List<i32> = (0..1000).collect();
+
+        for l in [&empty_list, &list] {
+            let mut counter = SeqCounter::default();
+
+            l.serialize(&mut counter).unwrap();
+
+            assert_eq!(counter.seq_len_hint, Some(l.len()));
+            assert_eq!(counter.elements, l.len());
+            assert!(counter.ended);
+        }
+    }
+
+    #[test]
+    fn test_serialize_json() {
+        let list: List<i32> = list![5, 6, 7, 8];
+        let mut sink: Vec<u8> = Vec::new();
+
+        list.serialize(&mut serde_json::Serializer::new(&mut sink)).unwrap();
+
+        assert_eq!(sink, b"[5,6,7,8]");
+
+        let decoded: List<i32> = serde_json::from_slice(&sink).unwrap();
+
+        assert_eq!(decoded, list);
+    }
+}
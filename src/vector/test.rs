@@ -378,8 +378,9 @@ mod internal {
 fn test_macro_vector() {
     let vector_1 = Vector::new().push_back(1);
     let vector_1_2_3 = Vector::new().push_back(1).push_back(2).push_back(3);
+    let empty_vector: Vector<u32> = vector![];
 
-    assert_eq!(Vector::<u32>::new(), vector![]);
+    assert_eq!(Vector::<u32>::new(), empty_vector);
     assert_eq!(vector_1, vector![1]);
     assert_eq!(vector_1_2_3, vector![1, 2, 3]);
 }
@@ -425,7 +426,7 @@ fn test_drop_last_drops_last_element() {
         vector = vector.drop_last().unwrap();
     }
 
-    assert_eq!(vector, Vector::new());
+    assert_eq!(vector, Vector::<i32>::new());
 }
 
 #[test]